@@ -0,0 +1,5 @@
+pub mod oneshot;
+pub mod local_oneshot;
+
+pub use oneshot::{oneshot, Oneshot, Complete, Canceled};
+pub use local_oneshot::{local_oneshot, LocalOneshot, LocalComplete};
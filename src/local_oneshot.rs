@@ -0,0 +1,194 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use {Future, Poll, Async};
+use oneshot::Canceled;
+use task::{self, Task};
+
+/// A future representing the completion of a computation happening elsewhere
+/// in memory, for use strictly on a single thread.
+///
+/// This is the `!Send`/`!Sync` counterpart to `oneshot::Oneshot`. It's
+/// created by the `local_oneshot` function.
+#[must_use = "futures do nothing unless polled"]
+pub struct LocalOneshot<T> {
+    inner: Rc<RefCell<Inner<T>>>,
+}
+
+/// Represents the completion half of a `local_oneshot` through which the
+/// result of a computation is signaled.
+///
+/// This is created by the `local_oneshot` function.
+pub struct LocalComplete<T> {
+    inner: Rc<RefCell<Inner<T>>>,
+}
+
+// Internal state of the `LocalOneshot`/`LocalComplete` pair above. This
+// mirrors `oneshot::Inner`, but because both halves are only ever touched
+// from the single thread that created them there's no need for an
+// `AtomicBool` or `Lock`; a plain `bool` and `RefCell::borrow_mut` are
+// sufficient and avoid the atomic fences and cache-line contention the
+// thread-safe version pays for.
+struct Inner<T> {
+    complete: bool,
+    data: Option<T>,
+    rx_task: Option<Task>,
+    tx_task: Option<Task>,
+}
+
+/// Creates a new in-memory oneshot used to represent completing a
+/// computation, for use strictly on a single thread.
+///
+/// This is the single-threaded counterpart to `oneshot`. The returned halves
+/// are `!Send` and `!Sync` and use no atomic operations or locking
+/// internally, which suits thread-per-core executors where many of these
+/// are created per task and the `Arc`/`AtomicBool` overhead of `oneshot`
+/// would otherwise add up.
+///
+/// # Examples
+///
+/// ```
+/// use futures::*;
+///
+/// let (c, p) = local_oneshot::<i32>();
+///
+/// c.send(3).unwrap();
+/// assert_eq!(p.wait(), Ok(3));
+/// ```
+pub fn local_oneshot<T>() -> (LocalComplete<T>, LocalOneshot<T>) {
+    let inner = Rc::new(RefCell::new(Inner {
+        complete: false,
+        data: None,
+        rx_task: None,
+        tx_task: None,
+    }));
+    let oneshot = LocalOneshot {
+        inner: inner.clone(),
+    };
+    let complete = LocalComplete {
+        inner: inner,
+    };
+    (complete, oneshot)
+}
+
+impl<T> LocalComplete<T> {
+    /// Completes this oneshot with a successful result.
+    ///
+    /// This function will consume `self` and indicate to the other end, the
+    /// `LocalOneshot`, that the value provided is the result of the
+    /// computation this represents.
+    ///
+    /// If the `LocalOneshot` has already gone away then this function
+    /// returns the value back inside `Err` rather than just dropping it.
+    pub fn send(self, t: T) -> Result<(), T> {
+        let mut inner = self.inner.borrow_mut();
+        if inner.complete {
+            return Err(t)
+        }
+        assert!(inner.data.is_none());
+        inner.data = Some(t);
+        Ok(())
+    }
+
+    /// Completes this oneshot with a successful result.
+    ///
+    /// This function will consume `self` and indicate to the other end, the
+    /// `LocalOneshot`, that the value provided is the result of the
+    /// computation this represents.
+    ///
+    /// If the `LocalOneshot` has already gone away then `t` is silently
+    /// dropped. Use `send` instead if you need to know whether the value
+    /// actually made it to the other side.
+    #[deprecated(note = "renamed to `send`")]
+    pub fn complete(self, t: T) {
+        drop(self.send(t));
+    }
+
+    /// Polls this `LocalComplete` half to detect whether the `LocalOneshot`
+    /// this has paired with has gone away.
+    ///
+    /// This function can be used to learn about when the `LocalOneshot`
+    /// (consumer) half has gone away and nothing will be able to receive a
+    /// message sent from `send`.
+    ///
+    /// Like `Future::poll`, this function will panic if it's not called from
+    /// within the context of a task. In other words, this should only ever
+    /// be called from inside another future.
+    ///
+    /// If `Ready` is returned then it means that the `LocalOneshot` has
+    /// disappeared and the result this `LocalComplete` would otherwise
+    /// produce should no longer be produced.
+    ///
+    /// If `NotReady` is returned then the `LocalOneshot` is still alive and
+    /// may be able to receive a message if sent. The current task, however,
+    /// is scheduled to receive a notification if the corresponding
+    /// `LocalOneshot` goes away.
+    pub fn poll_cancel(&mut self) -> Poll<(), ()> {
+        let mut inner = self.inner.borrow_mut();
+        if inner.complete {
+            return Ok(Async::Ready(()))
+        }
+        inner.tx_task = Some(task::park());
+        if inner.complete {
+            Ok(Async::Ready(()))
+        } else {
+            Ok(Async::NotReady)
+        }
+    }
+}
+
+impl<T> Drop for LocalComplete<T> {
+    fn drop(&mut self) {
+        // Flag that we're a completed `LocalComplete` and try to wake up a
+        // receiver. Whether or not we actually stored any data will get
+        // picked up and translated to either an item or cancellation.
+        let mut inner = self.inner.borrow_mut();
+        inner.complete = true;
+        if let Some(task) = inner.rx_task.take() {
+            drop(inner);
+            task.unpark();
+        }
+    }
+}
+
+impl<T> Future for LocalOneshot<T> {
+    type Item = T;
+    type Error = Canceled;
+
+    fn poll(&mut self) -> Poll<T, Canceled> {
+        let mut inner = self.inner.borrow_mut();
+
+        // Check to see if some data has arrived. If it hasn't then we need
+        // to block our task.
+        if !inner.complete {
+            inner.rx_task = Some(task::park());
+        }
+
+        if inner.complete {
+            match inner.data.take() {
+                Some(data) => Ok(data.into()),
+                None => Err(Canceled),
+            }
+        } else {
+            Ok(Async::NotReady)
+        }
+    }
+}
+
+impl<T> Drop for LocalOneshot<T> {
+    fn drop(&mut self) {
+        // Indicate to the `LocalComplete` that we're done, so any future
+        // calls to `poll_cancel` are weeded out.
+        let mut inner = self.inner.borrow_mut();
+        inner.complete = true;
+        inner.rx_task = None;
+
+        // If our `LocalComplete` wants to get notified of us going away, it
+        // would have stored something in `tx_task`. Pull that out and
+        // unpark it.
+        if let Some(task) = inner.tx_task.take() {
+            drop(inner);
+            task.unpark();
+        }
+    }
+}
@@ -41,9 +41,18 @@ pub struct Complete<T> {
 // the `Complete` goes away. The `tx_task` is the transmitter (`Complete`) task
 // to wake up when the `Oneshot` goes away.
 //
+// `closed` is deliberately kept separate from `complete`: `complete` means
+// "the pair is fully done and `data` will never be locked again", which
+// `Oneshot::poll`/`try_recv` and `Complete::send` rely on to take the `data`
+// lock without contention. `close` only asks future sends to be rejected
+// while the `Oneshot` may still be very much alive (and possibly about to
+// race a `Complete::send` for the `data` lock), so it must not flip
+// `complete` early or it would reintroduce that race.
+//
 // Also note that currently `tx_task
 struct Inner<T> {
     complete: AtomicBool,
+    closed: AtomicBool,
     data: Lock<Option<T>>,
     rx_task: Lock<Option<Task>>,
     tx_task: Lock<Option<Task>>,
@@ -77,11 +86,12 @@ struct Inner<T> {
 ///     }).wait();
 /// });
 ///
-/// c.complete(3);
+/// c.send(3).unwrap();
 /// ```
 pub fn oneshot<T>() -> (Complete<T>, Oneshot<T>) {
     let inner = Arc::new(Inner {
         complete: AtomicBool::new(false),
+        closed: AtomicBool::new(false),
         data: Lock::new(None),
         rx_task: Lock::new(None),
         tx_task: Lock::new(None),
@@ -101,7 +111,33 @@ impl<T> Complete<T> {
     /// This function will consume `self` and indicate to the other end, the
     /// `Oneshot`, that the error provided is the result of the computation this
     /// represents.
-    pub fn complete(mut self, t: T) {
+    ///
+    /// If the `Oneshot` has already gone away then `t` is silently dropped.
+    /// Use `send` instead if you need to know whether the value actually made
+    /// it to the other side.
+    #[deprecated(note = "renamed to `send`")]
+    pub fn complete(self, t: T) {
+        drop(self.send(t));
+    }
+
+    /// Completes this oneshot with a successful result.
+    ///
+    /// This function will consume `self` and indicate to the other end, the
+    /// `Oneshot`, that the value provided is the result of the computation
+    /// this represents.
+    ///
+    /// If the `Oneshot` has already gone away then this function returns the
+    /// value back inside `Err` rather than just dropping it, so the caller
+    /// can recover a possibly-expensive-to-compute result instead of losing
+    /// it silently.
+    pub fn send(mut self, t: T) -> Result<(), T> {
+        // Check first whether the other end has already gone away or has
+        // `close`d the channel. Either way the data is not to be stored, so
+        // just hand it back to the caller.
+        if self.is_canceled() {
+            return Err(t)
+        }
+
         // First up, flag that this method was called and then store the data.
         // Note that this lock acquisition should always succeed as it can only
         // interfere with `poll` in `Oneshot` which is only called when the
@@ -110,6 +146,7 @@ impl<T> Complete<T> {
         assert!(slot.is_none());
         *slot = Some(t);
         drop(slot);
+        Ok(())
     }
 
     /// Polls this `Complete` half to detect whether the `Oneshot` this has
@@ -136,7 +173,9 @@ impl<T> Complete<T> {
         // gone. This flag is set both in our destructor and the oneshot
         // destructor, but our destructor hasn't run yet so if it's set then the
         // oneshot is gone.
-        if self.inner.complete.load(SeqCst) {
+        // `is_canceled` also reports a `close`d `Oneshot`, which is treated
+        // the same way even though it is still alive.
+        if self.is_canceled() {
             return Ok(Async::Ready(()))
         }
 
@@ -158,12 +197,30 @@ impl<T> Complete<T> {
             Some(mut p) => *p = Some(handle),
             None => return Ok(Async::Ready(())),
         }
-        if self.inner.complete.load(SeqCst) {
+        if self.is_canceled() {
             Ok(Async::Ready(()))
         } else {
             Ok(Async::NotReady)
         }
     }
+
+    /// Returns whether the associated `Oneshot` has gone away, or has
+    /// explicitly `close`d this channel, without parking the current task.
+    ///
+    /// This is the fast path of `poll_cancel` exposed as a standalone query,
+    /// for use when a task context isn't available (or simply isn't worth
+    /// setting up just to make this check). It can be called from anywhere,
+    /// at any time, to make a quick decision -- e.g. aborting a loop
+    /// iteration or skipping an expensive step -- before committing to the
+    /// full `poll_cancel` dance.
+    ///
+    /// Note that a closed `Oneshot` is reported as canceled here even though
+    /// it may still be alive: from the sender's point of view a closed
+    /// channel is just as much a reason to stop as a dropped one, since
+    /// `send` will reject the value either way.
+    pub fn is_canceled(&self) -> bool {
+        self.inner.complete.load(SeqCst) || self.inner.closed.load(SeqCst)
+    }
 }
 
 impl<T> Drop for Complete<T> {
@@ -209,6 +266,64 @@ impl Error for Canceled {
     }
 }
 
+impl<T> Oneshot<T> {
+    /// Polls this `Oneshot` for a value without parking the current task.
+    ///
+    /// Unlike `poll`, this function never blocks waiting for the `Complete`
+    /// half to finish, which makes it usable outside of a task context (e.g.
+    /// from synchronous code while draining during shutdown). It simply
+    /// inspects the current state of the channel and reports what it sees.
+    ///
+    /// Returns `Ok(None)` if the `Complete` half is still alive and hasn't
+    /// sent a value yet. Returns `Ok(Some(t))` if a value has been sent.
+    /// Returns `Err(Canceled)` if the `Complete` half went away without
+    /// sending a value.
+    pub fn try_recv(&mut self) -> Result<Option<T>, Canceled> {
+        if !self.inner.complete.load(SeqCst) {
+            return Ok(None)
+        }
+
+        // The `complete` flag is set, so either a value has been stored or
+        // the `Complete` half is gone for good; either way nothing else will
+        // be touching `data` from here on, so this lock can't fail.
+        match self.inner.data.try_lock().unwrap().take() {
+            Some(data) => Ok(Some(data)),
+            None => Err(Canceled),
+        }
+    }
+
+    /// Closes this channel from the receiver's side, preventing any further
+    /// messages from being sent on the `Complete` half.
+    ///
+    /// This is useful for ensuring a computation is abandoned as soon as its
+    /// result is no longer wanted, without waiting for this `Oneshot` to be
+    /// dropped. If a value had already been sent before `close` was called
+    /// it isn't discarded; a subsequent `poll` or `try_recv` will still
+    /// return it. Any value sent *after* `close` is returned to the sender
+    /// via `Complete::send` instead of being stored.
+    ///
+    /// If the corresponding `Complete` is currently blocked in `poll_cancel`
+    /// it's woken up so it can observe the cancellation; `Complete::is_canceled`
+    /// also reports `true` once this has been called, even though this
+    /// `Oneshot` is still alive.
+    ///
+    /// Note this intentionally flips a separate `closed` flag rather than
+    /// the `complete` flag: `complete` is the signal `poll`/`try_recv` and
+    /// `send` rely on to know `data` will never be locked by the other side
+    /// again, and that's only true once this `Oneshot` is fully gone, not
+    /// merely closed. Setting `complete` here instead could make a
+    /// concurrent `send` and `try_recv`/`poll` both believe they have
+    /// uncontended access to `data`.
+    pub fn close(&mut self) {
+        self.inner.closed.store(true, SeqCst);
+        if let Some(mut slot) = self.inner.tx_task.try_lock() {
+            if let Some(task) = slot.take() {
+                drop(slot);
+                task.unpark();
+            }
+        }
+    }
+}
 
 impl<T> Future for Oneshot<T> {
     type Item = T;
@@ -0,0 +1,26 @@
+extern crate futures;
+
+use futures::*;
+use futures::oneshot::Canceled;
+use futures::local_oneshot::local_oneshot;
+
+#[test]
+fn send_then_receive() {
+    let (c, p) = local_oneshot::<i32>();
+    c.send(5).unwrap();
+    assert_eq!(p.wait(), Ok(5));
+}
+
+#[test]
+fn send_after_receiver_dropped_returns_value() {
+    let (c, p) = local_oneshot::<i32>();
+    drop(p);
+    assert_eq!(c.send(3), Err(3));
+}
+
+#[test]
+fn dropping_complete_without_sending_cancels_receiver() {
+    let (c, p) = local_oneshot::<i32>();
+    drop(c);
+    assert_eq!(p.wait(), Err(Canceled));
+}
@@ -0,0 +1,78 @@
+extern crate futures;
+
+use std::sync::mpsc;
+use std::thread;
+
+use futures::*;
+use futures::oneshot::oneshot;
+
+#[test]
+fn send_after_receiver_dropped_returns_value() {
+    let (c, p) = oneshot::<i32>();
+    drop(p);
+    assert_eq!(c.send(42), Err(42));
+}
+
+#[test]
+fn try_recv_not_ready_before_send() {
+    let (_c, mut p) = oneshot::<i32>();
+    assert_eq!(p.try_recv(), Ok(None));
+}
+
+#[test]
+fn try_recv_returns_value_after_send() {
+    let (c, mut p) = oneshot::<i32>();
+    c.send(1).unwrap();
+    assert_eq!(p.try_recv(), Ok(Some(1)));
+}
+
+#[test]
+fn try_recv_canceled_after_complete_dropped_without_sending() {
+    let (c, mut p) = oneshot::<i32>();
+    drop(c);
+    assert_eq!(p.try_recv(), Err(Canceled));
+}
+
+#[test]
+fn close_lets_already_sent_value_still_be_received() {
+    let (c, mut p) = oneshot::<i32>();
+    c.send(7).unwrap();
+    p.close();
+    assert_eq!(p.try_recv(), Ok(Some(7)));
+}
+
+#[test]
+fn send_after_close_is_rejected() {
+    let (c, mut p) = oneshot::<i32>();
+    p.close();
+    assert_eq!(c.send(5), Err(5));
+}
+
+#[test]
+fn close_marks_sender_canceled() {
+    let (mut c, mut p) = oneshot::<i32>();
+    assert!(!c.is_canceled());
+    p.close();
+    assert!(c.is_canceled());
+}
+
+// Runs `close` concurrently with `send` many times and checks that neither
+// side panics, nor does a `try_recv` made once the sender has finished.
+// `close` only sets the separate `closed` flag and never takes the `data`
+// lock, so the only `data` lock holders here are `send` and the later
+// `try_recv`.
+#[test]
+fn close_concurrent_with_send_does_not_panic() {
+    for _ in 0..100 {
+        let (c, mut p) = oneshot::<i32>();
+        let (ready_tx, ready_rx) = mpsc::channel();
+        let sender = thread::spawn(move || {
+            ready_tx.send(()).unwrap();
+            let _ = c.send(1);
+        });
+        ready_rx.recv().unwrap();
+        p.close();
+        sender.join().unwrap();
+        let _ = p.try_recv();
+    }
+}